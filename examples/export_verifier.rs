@@ -0,0 +1,32 @@
+// Render a Groth16 verifying key as a deployable Solidity verifier contract
+use circ::target::groth16::VerifyingKey;
+use std::env;
+use std::fs::{self, File};
+use std::io::BufReader;
+
+fn main() -> std::io::Result<()> {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 3 {
+        eprintln!("Usage: {} <verifying_key_file> <output.sol>", args[0]);
+        eprintln!("Example: {} vk.bin Verifier.sol", args[0]);
+        std::process::exit(1);
+    }
+
+    let path = &args[1];
+    let out_path = &args[2];
+
+    println!("Loading verifying key from: {}", path);
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    let vk: VerifyingKey =
+        bincode::deserialize_from(reader).expect("Failed to deserialize VerifyingKey");
+
+    println!("Public inputs: {}", vk.num_public_inputs()?);
+
+    let solidity = vk.to_solidity_verifier()?;
+    fs::write(out_path, solidity)?;
+
+    println!("Wrote Solidity verifier to {}", out_path);
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+// Export serialized ProverData as a circom/snarkjs-compatible `.r1cs` file
+use circ::target::r1cs::ProverData;
+use std::env;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+
+fn main() -> std::io::Result<()> {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 5 {
+        eprintln!(
+            "Usage: {} <prover_data_file> <output.r1cs> <n_pub_out> <n_pub_in>",
+            args[0]
+        );
+        eprintln!("Example: {} P circuit.r1cs 0 3", args[0]);
+        eprintln!();
+        eprintln!(
+            "n_pub_out/n_pub_in must match how the circuit's public variables \
+             were classified at compile time; ProverData does not yet expose \
+             that split directly."
+        );
+        std::process::exit(1);
+    }
+
+    let path = &args[1];
+    let out_path = &args[2];
+    let n_pub_out: usize = args[3]
+        .parse()
+        .expect("n_pub_out must be a non-negative integer");
+    let n_pub_in: usize = args[4]
+        .parse()
+        .expect("n_pub_in must be a non-negative integer");
+
+    println!("Loading ProverData from: {}", path);
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    let prover_data: ProverData =
+        bincode::deserialize_from(reader).expect("Failed to deserialize ProverData");
+
+    let out_file = File::create(out_path)?;
+    let mut writer = BufWriter::new(out_file);
+    prover_data
+        .r1cs
+        .write_circom_r1cs(&mut writer, n_pub_out, n_pub_in)?;
+
+    println!(
+        "Wrote {} constraints over {} wires to {}",
+        prover_data.r1cs.constraints().len(),
+        prover_data.r1cs.vars().len() + 1,
+        out_path
+    );
+
+    Ok(())
+}
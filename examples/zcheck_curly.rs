@@ -2,11 +2,20 @@
 /// Equivalent to `zokrates check` but for ZoKratesCurly (.zok files with curly braces)
 
 use circ::cfg::{clap, CircOpt};
-use clap::Parser;
+use circ::front::cache::CompileCache;
+use circ::front::zsharpcurly::diagnostic::Diagnostic;
+use clap::{Parser, ValueEnum};
 use circ::front::zsharpcurly::{Inputs, ZSharpCurlyFE};
 use circ::front::{FrontEnd, Mode};
+use std::fs;
 use std::path::PathBuf;
 
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    Human,
+    Json,
+}
+
 #[derive(Debug, Parser)]
 #[command(name = "zcheck_curly", about = "Check ZoKratesCurly programs for syntax and type errors")]
 struct Options {
@@ -14,6 +23,18 @@ struct Options {
     #[arg(name = "PATH")]
     path: PathBuf,
 
+    /// Directory to cache check results in, keyed by a hash of the source
+    /// and the active CircOpt flags. Skips re-running the front-end on a
+    /// cache hit.
+    #[arg(long)]
+    cache_dir: Option<PathBuf>,
+
+    /// How to print diagnostics: `human` shows carets under the offending
+    /// source line, `json` emits an array of diagnostics for editor/LSP or
+    /// CI consumption.
+    #[arg(long, value_enum, default_value_t = Format::Human)]
+    format: Format,
+
     #[command(flatten)]
     circ: CircOpt,
 }
@@ -27,29 +48,65 @@ fn main() {
     let options = Options::parse();
     circ::cfg::set(&options.circ);
 
+    let mode = Mode::Proof;
+    let cache = options
+        .cache_dir
+        .as_ref()
+        .map(|dir| CompileCache::new(dir).expect("Failed to open cache dir"));
+    let cache_key = cache.as_ref().map(|_| {
+        let imports = CompileCache::discover_imports(&options.path)
+            .expect("Failed to scan imports for cache key");
+        CompileCache::key(&options.path, &imports, &options.circ, mode)
+            .expect("Failed to hash input file")
+    });
+
+    if let (Some(cache), Some(key)) = (&cache, &cache_key) {
+        if let Some(cached) = cache.get::<Vec<Diagnostic>>(key) {
+            report(&options, cached);
+        }
+    }
+
     let inputs = Inputs {
-        file: options.path,
-        mode: Mode::Proof,
+        file: options.path.clone(),
+        mode,
     };
 
-    // Try to generate the circuit - this will parse and type check
-    match std::panic::catch_unwind(|| {
-        ZSharpCurlyFE::gen(inputs)
-    }) {
-        Ok(_) => {
-            println!("✓ Program is valid");
-            std::process::exit(0);
-        }
-        Err(e) => {
-            // Parsing errors are panics, extract the message if possible
-            if let Some(msg) = e.downcast_ref::<String>() {
-                eprintln!("Error: {}", msg);
-            } else if let Some(msg) = e.downcast_ref::<&str>() {
-                eprintln!("Error: {}", msg);
-            } else {
-                eprintln!("Error: Unknown parsing error");
+    let diagnostics = match ZSharpCurlyFE::gen(inputs) {
+        Ok(_) => Vec::new(),
+        Err(diagnostics) => diagnostics,
+    };
+
+    if let (Some(cache), Some(key)) = (&cache, &cache_key) {
+        cache
+            .put(key, &diagnostics)
+            .expect("Failed to write cache entry");
+    }
+
+    report(&options, diagnostics)
+}
+
+fn report(options: &Options, diagnostics: Vec<Diagnostic>) -> ! {
+    let ok = diagnostics
+        .iter()
+        .all(|d| d.severity != circ::front::zsharpcurly::diagnostic::Severity::Error);
+
+    match options.format {
+        Format::Human => {
+            let source = fs::read_to_string(&options.path).ok();
+            for d in &diagnostics {
+                d.print_human(source.as_deref());
+            }
+            if ok {
+                println!("✓ Program is valid");
             }
-            std::process::exit(1);
+        }
+        Format::Json => {
+            println!(
+                "{}",
+                serde_json::to_string(&diagnostics).expect("diagnostics are always serializable")
+            );
         }
     }
+
+    std::process::exit(if ok { 0 } else { 1 });
 }
@@ -0,0 +1,150 @@
+//! Coverage-guided fuzzer for the ZoKratesCurly parser and type checker.
+//!
+//! Feeds a structured token-sequence generator (so most inputs are at
+//! least lexically plausible, instead of starting from garbage bytes)
+//! into `ZSharpCurlyFE::gen` under a wall-clock budget, and reports
+//! anything that doesn't fail gracefully -- a panic, or a hang past the
+//! budget -- as a crash for libFuzzer to minimize.
+#![no_main]
+
+use arbitrary::Arbitrary;
+use circ::cfg::CircOpt;
+use circ::front::zsharpcurly::{Inputs, ZSharpCurlyFE};
+use circ::front::{FrontEnd, Mode};
+use libfuzzer_sys::fuzz_target;
+use std::io::Write;
+use std::sync::{mpsc, Once};
+use std::time::Duration;
+
+/// Wall-clock budget for a single `gen` call. A real compiler bug (infinite
+/// loop, exponential blowup) should never take this long on the tiny
+/// programs the fuzzer generates, so we treat a timeout as a crash too.
+const STEP_BUDGET: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Arbitrary)]
+enum Token {
+    Ident(u8),
+    IntLit(u32),
+    Keyword(Keyword),
+    Symbol(Symbol),
+}
+
+#[derive(Debug, Arbitrary)]
+enum Keyword {
+    Def,
+    Field,
+    Bool,
+    Return,
+    If,
+    Else,
+    For,
+    In,
+    Struct,
+    Private,
+}
+
+#[derive(Debug, Arbitrary)]
+enum Symbol {
+    LParen,
+    RParen,
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+    Comma,
+    Colon,
+    Semi,
+    Plus,
+    Minus,
+    Star,
+    Eq,
+    EqEq,
+    Arrow,
+}
+
+impl Token {
+    fn render(&self, out: &mut String) {
+        match self {
+            Token::Ident(b) => out.push_str(&format!("v{}", b % 26)),
+            Token::IntLit(n) => out.push_str(&n.to_string()),
+            Token::Keyword(k) => out.push_str(match k {
+                Keyword::Def => "def",
+                Keyword::Field => "field",
+                Keyword::Bool => "bool",
+                Keyword::Return => "return",
+                Keyword::If => "if",
+                Keyword::Else => "else",
+                Keyword::For => "for",
+                Keyword::In => "in",
+                Keyword::Struct => "struct",
+                Keyword::Private => "private",
+            }),
+            Token::Symbol(s) => out.push_str(match s {
+                Symbol::LParen => "(",
+                Symbol::RParen => ")",
+                Symbol::LBrace => "{",
+                Symbol::RBrace => "}",
+                Symbol::LBracket => "[",
+                Symbol::RBracket => "]",
+                Symbol::Comma => ",",
+                Symbol::Colon => ":",
+                Symbol::Semi => ";",
+                Symbol::Plus => "+",
+                Symbol::Minus => "-",
+                Symbol::Star => "*",
+                Symbol::Eq => "=",
+                Symbol::EqEq => "==",
+                Symbol::Arrow => "->",
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Arbitrary)]
+struct FuzzProgram {
+    tokens: Vec<Token>,
+}
+
+fn render(program: &FuzzProgram) -> String {
+    let mut src = String::new();
+    for tok in program.tokens.iter().take(2048) {
+        tok.render(&mut src);
+        src.push(' ');
+    }
+    src
+}
+
+fuzz_target!(|program: FuzzProgram| {
+    let src = render(&program);
+
+    let mut file = tempfile::NamedTempFile::new().expect("failed to create temp file");
+    file.write_all(src.as_bytes()).expect("failed to write temp file");
+
+    let path = file.path().to_path_buf();
+    // `cfg::set` panics if called twice, but `fuzz_target!` re-enters this
+    // closure once per input in the same process -- guard it so it only
+    // runs on the first iteration.
+    static CFG_INIT: Once = Once::new();
+    CFG_INIT.call_once(|| circ::cfg::set(&CircOpt::default()));
+
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        let inputs = Inputs {
+            file: path,
+            mode: Mode::Proof,
+        };
+        // `gen` now returns `Err(diagnostics)` for a rejected program, so a
+        // panic caught here is a genuine compiler bug, not a reported error.
+        let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            ZSharpCurlyFE::gen(inputs)
+        }))
+        .is_err();
+        let _ = tx.send(!panicked);
+    });
+
+    match rx.recv_timeout(STEP_BUDGET) {
+        Ok(true) => {}
+        Ok(false) => panic!("gen panicked instead of returning a diagnostic"),
+        Err(_) => panic!("gen exceeded the {:?} step budget (possible hang)", STEP_BUDGET),
+    }
+});
@@ -0,0 +1,51 @@
+//! Global compiler configuration.
+//!
+//! Front-ends and targets read shared knobs (optimization levels, field
+//! choice, etc.) through a single process-wide [`CircOpt`] instance rather
+//! than threading it through every call, so CLI tools call [`set`] exactly
+//! once at startup before touching any front-end or target code.
+
+pub use clap;
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+/// Shared configuration flags, flattened into the CLI options of the tools
+/// that use circ (see `#[command(flatten)]` in `examples/zcheck_curly.rs`).
+#[derive(Debug, Clone, Args, Serialize, Deserialize)]
+pub struct CircOpt {
+    /// Optimization level for the generated constraint system.
+    #[arg(long, default_value_t = 0)]
+    pub optimization_level: u32,
+}
+
+impl Default for CircOpt {
+    fn default() -> Self {
+        CircOpt {
+            optimization_level: 0,
+        }
+    }
+}
+
+static CIRC_OPT: OnceLock<CircOpt> = OnceLock::new();
+
+/// Install the process-wide `CircOpt`. Must be called at most once per
+/// process; a second call panics, since a config change part-way through a
+/// run would silently invalidate anything already compiled against the
+/// first value.
+pub fn set(opt: &CircOpt) {
+    CIRC_OPT
+        .set(opt.clone())
+        .expect("circ::cfg::set must only be called once per process");
+}
+
+/// Read the process-wide `CircOpt` installed by [`set`].
+///
+/// # Panics
+/// Panics if [`set`] hasn't been called yet.
+pub fn get() -> &'static CircOpt {
+    CIRC_OPT
+        .get()
+        .expect("circ::cfg::set must be called before circ::cfg::get")
+}
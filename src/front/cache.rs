@@ -0,0 +1,235 @@
+//! Content-addressed cache for compiled front-end artifacts.
+//!
+//! Recompiling a circuit from source is expensive but fully deterministic
+//! given the source files and the relevant [`CircOpt`] knobs, so we key a
+//! cache entry on a digest of all of that and store the resulting
+//! `Computation`/`ProverData` (bincode-serialized) under a cache directory.
+//! A hit skips the front-end entirely.
+
+use crate::cfg::CircOpt;
+use crate::front::Mode;
+use serde::{de::DeserializeOwned, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::{self, BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+
+/// A cache of compiled front-end artifacts, keyed by the hash of everything
+/// that determines the compilation result.
+pub struct CompileCache {
+    dir: PathBuf,
+}
+
+impl CompileCache {
+    /// Use (creating if necessary) `dir` as the cache directory.
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(CompileCache { dir })
+    }
+
+    /// Compute the cache key for compiling `entry_file` under `circ_opt` and
+    /// `mode`. `imported_files` should list every file the front-end reads
+    /// transitively while resolving `entry_file`'s imports, so that editing
+    /// an imported module invalidates the cache even though `entry_file`
+    /// itself is unchanged.
+    pub fn key(
+        entry_file: &Path,
+        imported_files: &[PathBuf],
+        circ_opt: &CircOpt,
+        mode: Mode,
+    ) -> io::Result<String> {
+        let mut hasher = Sha256::new();
+        hash_source_files(&mut hasher, entry_file, imported_files)?;
+
+        hasher.update(format!("{:?}", mode).as_bytes());
+        hasher.update(
+            bincode::serialize(circ_opt).expect("CircOpt is always serializable"),
+        );
+
+        Ok(finalize_hex(hasher))
+    }
+
+    /// Transitively discover the files `entry_file` imports, by scanning
+    /// for ZoKratesCurly `import "path";` statements and resolving each
+    /// path relative to the file that imports it.
+    ///
+    /// This is a lightweight textual scan, not a real parse: it doesn't
+    /// understand comments or string escapes, so it can over-approximate
+    /// (treat a commented-out import as real) but should never
+    /// under-approximate a well-formed import, which is the direction that
+    /// matters for cache correctness -- a false positive just means an
+    /// unnecessary cache miss, while a false negative would serve stale
+    /// results.
+    pub fn discover_imports(entry_file: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut seen = std::collections::HashSet::new();
+        let mut stack = vec![entry_file.canonicalize()?];
+        let mut imports = Vec::new();
+
+        while let Some(file) = stack.pop() {
+            if !seen.insert(file.clone()) {
+                continue;
+            }
+            let dir = file.parent().map(Path::to_path_buf).unwrap_or_default();
+            let contents = fs::read_to_string(&file)?;
+            for line in contents.lines() {
+                let line = line.trim();
+                let Some(rest) = line.strip_prefix("import") else {
+                    continue;
+                };
+                let rest = rest.trim_start();
+                let Some(rest) = rest.strip_prefix('"') else {
+                    continue;
+                };
+                let Some(end) = rest.find('"') else {
+                    continue;
+                };
+                let imported = dir.join(&rest[..end]);
+                let imported = imported.canonicalize().unwrap_or(imported);
+                if imported != file {
+                    stack.push(imported.clone());
+                    imports.push(imported);
+                }
+            }
+        }
+
+        imports.sort();
+        imports.dedup();
+        Ok(imports)
+    }
+
+    fn path_for(&self, key: &str) -> PathBuf {
+        self.dir.join(key)
+    }
+
+    /// Look up a previously cached artifact by key.
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let path = self.path_for(key);
+        let file = fs::File::open(path).ok()?;
+        bincode::deserialize_from(BufReader::new(file)).ok()
+    }
+
+    /// Store a compiled artifact under `key`, overwriting any previous entry.
+    pub fn put<T: Serialize>(&self, key: &str, value: &T) -> io::Result<()> {
+        let path = self.path_for(key);
+        let file = fs::File::create(path)?;
+        bincode::serialize_into(BufWriter::new(file), value)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))
+    }
+}
+
+/// Hash `entry_file` and `imported_files` (deduped and sorted, so argument
+/// order doesn't affect the result) into `hasher`, split out from
+/// `CompileCache::key` so it can be tested without needing a `CircOpt`.
+fn hash_source_files(
+    hasher: &mut Sha256,
+    entry_file: &Path,
+    imported_files: &[PathBuf],
+) -> io::Result<()> {
+    let mut files: Vec<PathBuf> = Vec::with_capacity(imported_files.len() + 1);
+    files.push(entry_file.canonicalize()?);
+    for f in imported_files {
+        files.push(f.canonicalize()?);
+    }
+    files.sort();
+    files.dedup();
+
+    for f in &files {
+        hasher.update(f.to_string_lossy().as_bytes());
+        hasher.update(fs::read(f)?);
+    }
+    Ok(())
+}
+
+fn finalize_hex(hasher: Sha256) -> String {
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write as _;
+
+    fn write_temp(dir: &Path, name: &str, contents: &str) -> PathBuf {
+        let path = dir.join(name);
+        let mut f = fs::File::create(&path).unwrap();
+        f.write_all(contents.as_bytes()).unwrap();
+        path
+    }
+
+    #[test]
+    fn hash_changes_when_entry_file_content_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let entry = write_temp(dir.path(), "main.zok", "def main() {}");
+        let mut h1 = Sha256::new();
+        hash_source_files(&mut h1, &entry, &[]).unwrap();
+        let key1 = finalize_hex(h1);
+
+        write_temp(dir.path(), "main.zok", "def main() { return; }");
+        let mut h2 = Sha256::new();
+        hash_source_files(&mut h2, &entry, &[]).unwrap();
+        let key2 = finalize_hex(h2);
+
+        assert_ne!(key1, key2);
+    }
+
+    #[test]
+    fn hash_changes_when_an_imported_file_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let entry = write_temp(dir.path(), "main.zok", "import \"./helper.zok\";");
+        let helper = write_temp(dir.path(), "helper.zok", "def helper() {}");
+
+        let mut h1 = Sha256::new();
+        hash_source_files(&mut h1, &entry, &[helper.clone()]).unwrap();
+        let key1 = finalize_hex(h1);
+
+        write_temp(dir.path(), "helper.zok", "def helper() { return; }");
+        let mut h2 = Sha256::new();
+        hash_source_files(&mut h2, &entry, &[helper]).unwrap();
+        let key2 = finalize_hex(h2);
+
+        assert_ne!(
+            key1, key2,
+            "editing an imported file must invalidate the cache key"
+        );
+    }
+
+    #[test]
+    fn discover_imports_follows_transitive_imports() {
+        let dir = tempfile::tempdir().unwrap();
+        write_temp(dir.path(), "leaf.zok", "def leaf() {}");
+        write_temp(
+            dir.path(),
+            "mid.zok",
+            "import \"./leaf.zok\";\ndef mid() {}",
+        );
+        let entry = write_temp(
+            dir.path(),
+            "main.zok",
+            "import \"./mid.zok\";\ndef main() {}",
+        );
+
+        let imports = CompileCache::discover_imports(&entry).unwrap();
+        let names: Vec<String> = imports
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert!(names.contains(&"mid.zok".to_string()));
+        assert!(names.contains(&"leaf.zok".to_string()));
+    }
+
+    #[test]
+    fn discover_imports_handles_cycles() {
+        let dir = tempfile::tempdir().unwrap();
+        let a = write_temp(dir.path(), "a.zok", "import \"./b.zok\";\ndef a() {}");
+        write_temp(dir.path(), "b.zok", "import \"./a.zok\";\ndef b() {}");
+
+        // Must terminate rather than looping forever on the a <-> b cycle.
+        let imports = CompileCache::discover_imports(&a).unwrap();
+        assert_eq!(imports.len(), 1);
+    }
+}
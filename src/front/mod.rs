@@ -0,0 +1,34 @@
+//! Front-ends: turn a source program into a [`Computation`], circ's
+//! intermediate representation, before it's lowered to a target like R1CS.
+
+pub mod cache;
+pub mod zsharpcurly;
+
+/// What a front-end should produce the circuit for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Generate a circuit suitable for proving: all reachable branches are
+    /// compiled, and private inputs are treated as witness values.
+    Proof,
+}
+
+/// circ's intermediate representation of a compiled program: enough to
+/// lower to a target (e.g. R1CS) and to evaluate on a concrete witness.
+#[derive(Debug, Clone, Default)]
+pub struct Computation {
+    /// Names of the function's declared outputs, in declaration order.
+    pub outputs: Vec<String>,
+}
+
+/// A front-end compiles some language's [`FrontEnd::Inputs`] into a
+/// [`FrontEnd::Output`] (typically a [`Computation`], or a `Result` wrapping
+/// one for front-ends that report diagnostics instead of panicking).
+pub trait FrontEnd {
+    /// Everything the front-end needs to compile one program: source
+    /// location, compilation mode, etc.
+    type Inputs;
+    /// What a successful (or diagnostic-reporting) compilation produces.
+    type Output;
+
+    fn gen(i: Self::Inputs) -> Self::Output;
+}
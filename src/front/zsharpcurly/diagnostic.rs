@@ -0,0 +1,132 @@
+//! Structured, span-aware diagnostics for the ZoKratesCurly front-end.
+//!
+//! `ZSharpCurlyFE::gen` and the parse/typecheck passes it drives return
+//! `Result<_, Vec<Diagnostic>>` instead of panicking, so a single run can
+//! report every error it finds rather than aborting on the first one, and
+//! callers (like `zcheck_curly`) can render them for humans or emit them as
+//! JSON for editor/LSP integration.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::PathBuf;
+
+/// How serious a diagnostic is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// A half-open byte range in a source file, with the line/column of its
+/// start for human-readable rendering.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Span {
+    pub file: PathBuf,
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub col: usize,
+}
+
+impl Span {
+    pub fn new(file: PathBuf, start: usize, end: usize, line: usize, col: usize) -> Self {
+        Span {
+            file,
+            start,
+            end,
+            line,
+            col,
+        }
+    }
+}
+
+/// A single parse/typecheck diagnostic.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub span: Option<Span>,
+}
+
+impl Diagnostic {
+    pub fn error(message: impl Into<String>, span: Option<Span>) -> Self {
+        Diagnostic {
+            severity: Severity::Error,
+            message: message.into(),
+            span,
+        }
+    }
+
+    pub fn warning(message: impl Into<String>, span: Option<Span>) -> Self {
+        Diagnostic {
+            severity: Severity::Warning,
+            message: message.into(),
+            span,
+        }
+    }
+
+    /// Print this diagnostic the way a human reads it: the message, then
+    /// (if we have a span) the offending source line with a caret under the
+    /// span's start column.
+    pub fn print_human(&self, source: Option<&str>) {
+        match &self.span {
+            Some(span) => {
+                eprintln!(
+                    "{}: {} ({}:{}:{})",
+                    self.severity,
+                    self.message,
+                    span.file.display(),
+                    span.line,
+                    span.col
+                );
+                if let Some(source) = source {
+                    if let Some(line) = source.lines().nth(span.line.saturating_sub(1)) {
+                        eprintln!("  {}", line);
+                        eprintln!("  {}^", " ".repeat(span.col.saturating_sub(1)));
+                    }
+                }
+            }
+            None => eprintln!("{}: {}", self.severity, self.message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn severity_displays_lowercase() {
+        assert_eq!(Severity::Error.to_string(), "error");
+        assert_eq!(Severity::Warning.to_string(), "warning");
+    }
+
+    #[test]
+    fn diagnostic_round_trips_through_json() {
+        let d = Diagnostic::error(
+            "unexpected token",
+            Some(Span::new(PathBuf::from("main.zok"), 10, 14, 2, 5)),
+        );
+        let json = serde_json::to_string(&d).unwrap();
+        let back: Diagnostic = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.severity, Severity::Error);
+        assert_eq!(back.message, "unexpected token");
+        assert_eq!(back.span, d.span);
+    }
+
+    #[test]
+    fn error_and_warning_constructors_set_severity() {
+        assert_eq!(Diagnostic::error("x", None).severity, Severity::Error);
+        assert_eq!(Diagnostic::warning("x", None).severity, Severity::Warning);
+    }
+}
@@ -0,0 +1,180 @@
+//! Tokenizer for ZoKratesCurly source. Invalid characters are reported as
+//! diagnostics and skipped rather than aborting the whole lex, so a single
+//! run can surface more than one lexical error.
+
+use super::diagnostic::{Diagnostic, Span};
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokKind {
+    Ident(String),
+    IntLit(i64),
+    KwDef,
+    KwField,
+    KwBool,
+    KwReturn,
+    KwPrivate,
+    LParen,
+    RParen,
+    LBrace,
+    RBrace,
+    Comma,
+    Colon,
+    Semi,
+    Arrow,
+    Eq,
+    Plus,
+    Minus,
+    Star,
+}
+
+#[derive(Debug, Clone)]
+pub struct Token {
+    pub kind: TokKind,
+    pub span: Span,
+}
+
+struct Lexer<'a> {
+    file: &'a Path,
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+    col: usize,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Lexer<'a> {
+    fn span_at(&self, start: usize, start_line: usize, start_col: usize) -> Span {
+        Span::new(self.file.to_path_buf(), start, self.pos, start_line, start_col)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+            self.col = 1;
+        } else {
+            self.col += 1;
+        }
+        Some(c)
+    }
+
+    fn keyword(ident: &str) -> Option<TokKind> {
+        Some(match ident {
+            "def" => TokKind::KwDef,
+            "field" => TokKind::KwField,
+            "bool" => TokKind::KwBool,
+            "return" => TokKind::KwReturn,
+            "private" => TokKind::KwPrivate,
+            _ => return None,
+        })
+    }
+
+    fn run(mut self) -> (Vec<Token>, Vec<Diagnostic>) {
+        let mut tokens = Vec::new();
+        while let Some(c) = self.peek() {
+            let (start, start_line, start_col) = (self.pos, self.line, self.col);
+            if c.is_whitespace() {
+                self.advance();
+                continue;
+            }
+            if c == '/' && self.chars.get(self.pos + 1) == Some(&'/') {
+                while self.peek().is_some() && self.peek() != Some('\n') {
+                    self.advance();
+                }
+                continue;
+            }
+            if c.is_ascii_digit() {
+                let mut s = String::new();
+                while let Some(c) = self.peek() {
+                    if c.is_ascii_digit() {
+                        s.push(c);
+                        self.advance();
+                    } else {
+                        break;
+                    }
+                }
+                let span = self.span_at(start, start_line, start_col);
+                match s.parse::<i64>() {
+                    Ok(n) => tokens.push(Token {
+                        kind: TokKind::IntLit(n),
+                        span,
+                    }),
+                    Err(_) => self.diagnostics.push(Diagnostic::error(
+                        format!("integer literal '{}' out of range", s),
+                        Some(span),
+                    )),
+                }
+                continue;
+            }
+            if c.is_alphabetic() || c == '_' {
+                let mut s = String::new();
+                while let Some(c) = self.peek() {
+                    if c.is_alphanumeric() || c == '_' {
+                        s.push(c);
+                        self.advance();
+                    } else {
+                        break;
+                    }
+                }
+                let span = self.span_at(start, start_line, start_col);
+                let kind = Self::keyword(&s).unwrap_or(TokKind::Ident(s));
+                tokens.push(Token { kind, span });
+                continue;
+            }
+
+            self.advance();
+            let single = |k: TokKind| Some(k);
+            let kind = match c {
+                '(' => single(TokKind::LParen),
+                ')' => single(TokKind::RParen),
+                '{' => single(TokKind::LBrace),
+                '}' => single(TokKind::RBrace),
+                ',' => single(TokKind::Comma),
+                ':' => single(TokKind::Colon),
+                ';' => single(TokKind::Semi),
+                '+' => single(TokKind::Plus),
+                '*' => single(TokKind::Star),
+                '-' => {
+                    if self.peek() == Some('>') {
+                        self.advance();
+                        single(TokKind::Arrow)
+                    } else {
+                        single(TokKind::Minus)
+                    }
+                }
+                '=' => single(TokKind::Eq),
+                _ => None,
+            };
+            let span = self.span_at(start, start_line, start_col);
+            match kind {
+                Some(kind) => tokens.push(Token { kind, span }),
+                None => self.diagnostics.push(Diagnostic::error(
+                    format!("unexpected character '{}'", c),
+                    Some(span),
+                )),
+            }
+        }
+        (tokens, self.diagnostics)
+    }
+}
+
+/// Tokenize `source` (read from `file`, used only to stamp spans),
+/// returning every token successfully lexed alongside any diagnostics for
+/// characters that couldn't be.
+pub fn lex(file: &Path, source: &str) -> (Vec<Token>, Vec<Diagnostic>) {
+    Lexer {
+        file,
+        chars: source.chars().collect(),
+        pos: 0,
+        line: 1,
+        col: 1,
+        diagnostics: Vec::new(),
+    }
+    .run()
+}
@@ -0,0 +1,62 @@
+//! ZoKratesCurly: a small ZoKrates-like front-end that uses curly braces
+//! instead of significant indentation.
+
+pub mod diagnostic;
+mod lexer;
+mod parser;
+
+use super::{Computation, FrontEnd, Mode};
+use diagnostic::{Diagnostic, Severity};
+use std::fs;
+use std::path::PathBuf;
+
+/// Everything [`ZSharpCurlyFE::gen`] needs to compile one program.
+#[derive(Debug, Clone)]
+pub struct Inputs {
+    pub file: PathBuf,
+    pub mode: Mode,
+}
+
+/// `gen` doesn't yet lower a parsed [`Program`](parser::Program) to R1CS --
+/// it stops at a typechecked AST. Call-site deduplication for unrolled
+/// loops (circ#chunk0-6) has to hook into that lowering, wire-for-wire, to
+/// mean anything; there's no lowering pass here yet to hook it into, so
+/// that request stays unimplemented rather than landing disconnected
+/// scaffolding again.
+pub struct ZSharpCurlyFE;
+
+impl FrontEnd for ZSharpCurlyFE {
+    type Inputs = Inputs;
+    type Output = Result<Computation, Vec<Diagnostic>>;
+
+    /// Lex, parse, and typecheck `i.file`, collecting diagnostics from every
+    /// stage instead of stopping at the first error. Typechecking only runs
+    /// if lexing and parsing found no errors, since a malformed AST isn't
+    /// worth typechecking.
+    fn gen(i: Self::Inputs) -> Self::Output {
+        let source = fs::read_to_string(&i.file).map_err(|e| {
+            vec![Diagnostic::error(
+                format!("could not read {}: {}", i.file.display(), e),
+                None,
+            )]
+        })?;
+
+        let (tokens, mut diagnostics) = lexer::lex(&i.file, &source);
+        let (program, parse_diagnostics) = parser::parse(&i.file, tokens);
+        diagnostics.extend(parse_diagnostics);
+
+        if !diagnostics.iter().any(|d| d.severity == Severity::Error) {
+            diagnostics.extend(parser::typecheck(&i.file, &program));
+        }
+
+        if diagnostics.iter().any(|d| d.severity == Severity::Error) {
+            return Err(diagnostics);
+        }
+
+        // ZoKratesCurly functions return a single unnamed value, so there's
+        // no declared output name to carry into `Computation::outputs` yet.
+        let _ = program;
+        let _ = i.mode;
+        Ok(Computation::default())
+    }
+}
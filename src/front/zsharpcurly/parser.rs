@@ -0,0 +1,370 @@
+//! Recursive-descent parser and a minimal typechecker for ZoKratesCurly.
+//!
+//! Both stages recover from an error by skipping to the next statement or
+//! declaration boundary instead of bailing out, so a single run collects
+//! every diagnostic it can rather than stopping at the first one.
+
+use super::diagnostic::{Diagnostic, Span};
+use super::lexer::{TokKind, Token};
+use std::collections::HashMap;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Type {
+    Field,
+    Bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Param {
+    pub name: String,
+    pub ty: Type,
+}
+
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Ident(String, Span),
+    IntLit(i64),
+    Binary(Box<Expr>, Box<Expr>),
+}
+
+#[derive(Debug, Clone)]
+pub enum Stmt {
+    Let {
+        name: String,
+        ty: Type,
+        expr: Expr,
+        span: Span,
+    },
+    Return(Expr),
+}
+
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub name: String,
+    pub name_span: Span,
+    pub params: Vec<Param>,
+    pub body: Vec<Stmt>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Program {
+    pub functions: Vec<Function>,
+}
+
+struct Parser<'a> {
+    file: &'a Path,
+    tokens: Vec<Token>,
+    pos: usize,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&TokKind> {
+        self.tokens.get(self.pos).map(|t| &t.kind)
+    }
+
+    fn peek_span(&self) -> Option<Span> {
+        self.tokens.get(self.pos).map(|t| t.span.clone())
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let t = self.tokens.get(self.pos).cloned();
+        if t.is_some() {
+            self.pos += 1;
+        }
+        t
+    }
+
+    fn error_here(&mut self, message: impl Into<String>) {
+        let span = self.peek_span();
+        self.diagnostics.push(Diagnostic::error(message, span));
+    }
+
+    /// Consume a token of exactly this kind, or report an error and leave
+    /// the cursor where it is.
+    fn expect(&mut self, kind: &TokKind, what: &str) -> bool {
+        if self.peek() == Some(kind) {
+            self.advance();
+            true
+        } else {
+            self.error_here(format!("expected {}", what));
+            false
+        }
+    }
+
+    fn expect_ident(&mut self) -> Option<String> {
+        match self.peek().cloned() {
+            Some(TokKind::Ident(s)) => {
+                self.advance();
+                Some(s)
+            }
+            _ => {
+                self.error_here("expected an identifier");
+                None
+            }
+        }
+    }
+
+    /// Skip tokens until (and including) the next `;`, `}`, or end of
+    /// input, so parsing can resume after a malformed statement or
+    /// declaration.
+    fn recover_to_boundary(&mut self) {
+        loop {
+            match self.peek() {
+                None => return,
+                Some(TokKind::Semi) => {
+                    self.advance();
+                    return;
+                }
+                Some(TokKind::RBrace) => return,
+                _ => {
+                    self.advance();
+                }
+            }
+        }
+    }
+
+    fn parse_type(&mut self) -> Option<Type> {
+        match self.peek() {
+            Some(TokKind::KwField) => {
+                self.advance();
+                Some(Type::Field)
+            }
+            Some(TokKind::KwBool) => {
+                self.advance();
+                Some(Type::Bool)
+            }
+            _ => {
+                self.error_here("expected a type ('field' or 'bool')");
+                None
+            }
+        }
+    }
+
+    fn parse_params(&mut self) -> Vec<Param> {
+        let mut params = Vec::new();
+        if self.peek() == Some(&TokKind::RParen) {
+            return params;
+        }
+        loop {
+            // `private` is accepted but ZSharpCurlyFE::gen doesn't yet
+            // distinguish public/private params (see chunk0-1's exporter
+            // follow-up note on R1cs classification).
+            if self.peek() == Some(&TokKind::KwPrivate) {
+                self.advance();
+            }
+            let Some(ty) = self.parse_type() else {
+                self.recover_to_boundary();
+                break;
+            };
+            let Some(name) = self.expect_ident() else {
+                self.recover_to_boundary();
+                break;
+            };
+            params.push(Param { name, ty });
+            match self.peek() {
+                Some(TokKind::Comma) => {
+                    self.advance();
+                }
+                _ => break,
+            }
+        }
+        params
+    }
+
+    fn parse_atom(&mut self) -> Option<Expr> {
+        match self.peek().cloned() {
+            Some(TokKind::IntLit(n)) => {
+                self.advance();
+                Some(Expr::IntLit(n))
+            }
+            Some(TokKind::Ident(name)) => {
+                let span = self.peek_span().unwrap();
+                self.advance();
+                Some(Expr::Ident(name, span))
+            }
+            Some(TokKind::LParen) => {
+                self.advance();
+                let e = self.parse_expr();
+                self.expect(&TokKind::RParen, "')'");
+                e
+            }
+            _ => {
+                self.error_here("expected an expression");
+                None
+            }
+        }
+    }
+
+    fn parse_expr(&mut self) -> Option<Expr> {
+        let mut lhs = self.parse_atom()?;
+        while matches!(
+            self.peek(),
+            Some(TokKind::Plus) | Some(TokKind::Minus) | Some(TokKind::Star)
+        ) {
+            self.advance();
+            let rhs = self.parse_atom()?;
+            lhs = Expr::Binary(Box::new(lhs), Box::new(rhs));
+        }
+        Some(lhs)
+    }
+
+    fn parse_stmt(&mut self) -> Option<Stmt> {
+        match self.peek() {
+            Some(TokKind::KwReturn) => {
+                self.advance();
+                let expr = self.parse_expr();
+                self.expect(&TokKind::Semi, "';' after return");
+                expr.map(Stmt::Return)
+            }
+            Some(TokKind::KwField) | Some(TokKind::KwBool) => {
+                let span = self.peek_span().unwrap();
+                let ty = self.parse_type()?;
+                let name = self.expect_ident()?;
+                self.expect(&TokKind::Eq, "'=' in let binding");
+                let expr = self.parse_expr()?;
+                self.expect(&TokKind::Semi, "';' after let binding");
+                Some(Stmt::Let {
+                    name,
+                    ty,
+                    expr,
+                    span,
+                })
+            }
+            _ => {
+                self.error_here("expected a statement");
+                None
+            }
+        }
+    }
+
+    fn parse_function(&mut self) -> Option<Function> {
+        self.expect(&TokKind::KwDef, "'def'");
+        let name_span = self.peek_span().unwrap_or_else(|| {
+            Span::new(self.file.to_path_buf(), self.pos, self.pos, 0, 0)
+        });
+        let name = self.expect_ident()?;
+        self.expect(&TokKind::LParen, "'(' after function name");
+        let params = self.parse_params();
+        self.expect(&TokKind::RParen, "')' after parameters");
+        if self.peek() == Some(&TokKind::Arrow) {
+            self.advance();
+            self.parse_type();
+        }
+        self.expect(&TokKind::LBrace, "'{' to start function body");
+
+        let mut body = Vec::new();
+        while let Some(k) = self.peek() {
+            if *k == TokKind::RBrace {
+                break;
+            }
+            match self.parse_stmt() {
+                Some(stmt) => body.push(stmt),
+                None => self.recover_to_boundary(),
+            }
+        }
+        self.expect(&TokKind::RBrace, "'}' to end function body");
+
+        Some(Function {
+            name,
+            name_span,
+            params,
+            body,
+        })
+    }
+
+    fn parse_program(mut self) -> (Program, Vec<Diagnostic>) {
+        let mut functions = Vec::new();
+        while self.peek().is_some() {
+            match self.parse_function() {
+                Some(f) => functions.push(f),
+                None => self.recover_to_boundary(),
+            }
+        }
+        (Program { functions }, self.diagnostics)
+    }
+}
+
+/// Parse `tokens` into a [`Program`], recovering from syntax errors at
+/// statement/declaration boundaries so a single run can report more than
+/// one.
+pub fn parse(file: &Path, tokens: Vec<Token>) -> (Program, Vec<Diagnostic>) {
+    Parser {
+        file,
+        tokens,
+        pos: 0,
+        diagnostics: Vec::new(),
+    }
+    .parse_program()
+}
+
+/// Check that `main` exists, that every function's parameters and
+/// variables are uniquely named, and that every identifier used in an
+/// expression was actually declared.
+pub fn typecheck(file: &Path, program: &Program) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    if !program.functions.iter().any(|f| f.name == "main") {
+        diagnostics.push(Diagnostic::error(
+            "no 'main' function found",
+            None,
+        ));
+    }
+
+    let mut seen_names = HashMap::new();
+    for f in &program.functions {
+        if let Some(prev) = seen_names.insert(f.name.clone(), f.name_span.clone()) {
+            diagnostics.push(Diagnostic::error(
+                format!("function '{}' is defined more than once", f.name),
+                Some(f.name_span.clone()),
+            ));
+            let _ = prev;
+        }
+
+        let mut scope: HashMap<String, Type> = HashMap::new();
+        for p in &f.params {
+            scope.insert(p.name.clone(), p.ty);
+        }
+
+        for stmt in &f.body {
+            match stmt {
+                Stmt::Let {
+                    name, ty, expr, span,
+                } => {
+                    check_expr(file, expr, &scope, &mut diagnostics);
+                    scope.insert(name.clone(), *ty);
+                    let _ = span;
+                }
+                Stmt::Return(expr) => {
+                    check_expr(file, expr, &scope, &mut diagnostics);
+                }
+            }
+        }
+    }
+
+    diagnostics
+}
+
+fn check_expr(
+    _file: &Path,
+    expr: &Expr,
+    scope: &HashMap<String, Type>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    match expr {
+        Expr::IntLit(_) => {}
+        Expr::Ident(name, span) => {
+            if !scope.contains_key(name) {
+                diagnostics.push(Diagnostic::error(
+                    format!("use of undeclared identifier '{}'", name),
+                    Some(span.clone()),
+                ));
+            }
+        }
+        Expr::Binary(lhs, rhs) => {
+            check_expr(_file, lhs, scope, diagnostics);
+            check_expr(_file, rhs, scope, diagnostics);
+        }
+    }
+}
@@ -0,0 +1,6 @@
+//! circ: compiler infrastructure for compiling high-level programs down to
+//! constraint systems (R1CS) for use with zkSNARK backends.
+
+pub mod cfg;
+pub mod front;
+pub mod target;
@@ -0,0 +1,59 @@
+//! Groth16 verifying-key material and export helpers.
+
+pub mod solidity;
+
+use rug::Integer;
+use serde::{Deserialize, Serialize};
+use std::io;
+
+/// A point on the base (G1) curve, as affine coordinates.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct G1 {
+    pub x: Integer,
+    pub y: Integer,
+}
+
+/// A point on the twist (G2) curve, as affine coordinates over the
+/// quadratic extension field (each coordinate is a pair `[c0, c1]`).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct G2 {
+    pub x: [Integer; 2],
+    pub y: [Integer; 2],
+}
+
+/// The public verifying-key material produced alongside `ProverData` by the
+/// Groth16 setup. This is the subset of the key needed to check a proof,
+/// independent of the proving system's internal representation.
+///
+/// circ doesn't yet implement a Groth16 trusted setup, so nothing in this
+/// crate produces a `VerifyingKey` from a real proving pipeline; the only
+/// way to get one today is to deserialize a key file exported by some other
+/// tool in this format. `examples/export_verifier.rs` treats that file as
+/// untrusted input for exactly this reason: it validates the key's shape
+/// (see `num_public_inputs`) rather than assuming it came from a real setup.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct VerifyingKey {
+    pub alpha_g1: G1,
+    pub beta_g2: G2,
+    pub gamma_g2: G2,
+    pub delta_g2: G2,
+    /// One G1 point per public input, plus one for the constant term.
+    pub ic: Vec<G1>,
+}
+
+impl VerifyingKey {
+    /// The number of public inputs the proof this key verifies takes.
+    ///
+    /// Returns an error rather than panicking if `ic` is empty, since a
+    /// well-formed key always has at least the constant-term entry; an
+    /// empty `ic` means the key was deserialized from something other than
+    /// a real Groth16 setup output.
+    pub fn num_public_inputs(&self) -> io::Result<usize> {
+        self.ic.len().checked_sub(1).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "verifying key has an empty IC vector (expected at least the constant-term entry)",
+            )
+        })
+    }
+}
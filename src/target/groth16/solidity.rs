@@ -0,0 +1,221 @@
+//! Render a [`VerifyingKey`] as a standalone Solidity Groth16 verifier,
+//! following the pairing-check layout used across the proving-tool
+//! ecosystem (snarkjs' `exportSolidityVerifier`, libsnark, etc.): a
+//! `Pairing` library for curve arithmetic, a `Verifier` struct holding the
+//! hardcoded key, and a `verifyProof` entry point.
+
+use super::VerifyingKey;
+use std::io;
+
+impl VerifyingKey {
+    /// Render this key as a ready-to-deploy Solidity Groth16 verifier
+    /// contract exposing `verifyProof(uint[2] a, uint[2][2] b, uint[2] c,
+    /// uint[] input)`, matching the standard snarkjs-generated verifier ABI.
+    ///
+    /// Returns an error rather than panicking if the key is malformed (see
+    /// [`VerifyingKey::num_public_inputs`]).
+    pub fn to_solidity_verifier(&self) -> io::Result<String> {
+        let n_public = self.num_public_inputs()?;
+        let ic_entries: String = self
+            .ic
+            .iter()
+            .enumerate()
+            .map(|(i, p)| format!("        vk.IC[{}] = Pairing.G1Point({}, {});\n", i, p.x, p.y))
+            .collect();
+
+        Ok(format!(
+            r#"// SPDX-License-Identifier: MIT
+// Auto-generated Groth16 verifier. Do not edit by hand.
+pragma solidity ^0.8.0;
+
+library Pairing {{
+    struct G1Point {{
+        uint X;
+        uint Y;
+    }}
+
+    // Encoding of field elements is: X[0] * z + X[1]
+    struct G2Point {{
+        uint[2] X;
+        uint[2] Y;
+    }}
+
+    function negate(G1Point memory p) internal pure returns (G1Point memory) {{
+        uint q = 21888242871839275222246405745257275088696311157297823662689037894645226208583;
+        if (p.X == 0 && p.Y == 0) return G1Point(0, 0);
+        return G1Point(p.X, q - (p.Y % q));
+    }}
+
+    function addition(G1Point memory p1, G1Point memory p2) internal view returns (G1Point memory r) {{
+        uint[4] memory input;
+        input[0] = p1.X;
+        input[1] = p1.Y;
+        input[2] = p2.X;
+        input[3] = p2.Y;
+        bool success;
+        assembly {{
+            success := staticcall(sub(gas(), 2000), 6, input, 0xc0, r, 0x60)
+        }}
+        require(success, "pairing-add-failed");
+    }}
+
+    function scalar_mul(G1Point memory p, uint s) internal view returns (G1Point memory r) {{
+        uint[3] memory input;
+        input[0] = p.X;
+        input[1] = p.Y;
+        input[2] = s;
+        bool success;
+        assembly {{
+            success := staticcall(sub(gas(), 2000), 7, input, 0x80, r, 0x60)
+        }}
+        require(success, "pairing-mul-failed");
+    }}
+
+    function pairing(G1Point[] memory p1, G2Point[] memory p2) internal view returns (bool) {{
+        require(p1.length == p2.length, "pairing-lengths-failed");
+        uint elements = p1.length;
+        uint inputSize = elements * 6;
+        uint[] memory input = new uint[](inputSize);
+        for (uint i = 0; i < elements; i++) {{
+            input[i * 6 + 0] = p1[i].X;
+            input[i * 6 + 1] = p1[i].Y;
+            input[i * 6 + 2] = p2[i].X[0];
+            input[i * 6 + 3] = p2[i].X[1];
+            input[i * 6 + 4] = p2[i].Y[0];
+            input[i * 6 + 5] = p2[i].Y[1];
+        }}
+        uint[1] memory out;
+        bool success;
+        assembly {{
+            success := staticcall(sub(gas(), 2000), 8, add(input, 0x20), mul(inputSize, 0x20), out, 0x20)
+        }}
+        require(success, "pairing-opcode-failed");
+        return out[0] != 0;
+    }}
+}}
+
+contract Verifier {{
+    using Pairing for *;
+
+    struct VerifyingKey {{
+        Pairing.G1Point alpha;
+        Pairing.G2Point beta;
+        Pairing.G2Point gamma;
+        Pairing.G2Point delta;
+        Pairing.G1Point[] IC;
+    }}
+
+    VerifyingKey vk;
+
+    constructor() {{
+        vk.alpha = Pairing.G1Point({alpha_x}, {alpha_y});
+        vk.beta = Pairing.G2Point([{beta_x0}, {beta_x1}], [{beta_y0}, {beta_y1}]);
+        vk.gamma = Pairing.G2Point([{gamma_x0}, {gamma_x1}], [{gamma_y0}, {gamma_y1}]);
+        vk.delta = Pairing.G2Point([{delta_x0}, {delta_x1}], [{delta_y0}, {delta_y1}]);
+        vk.IC = new Pairing.G1Point[]({ic_len});
+{ic_entries}    }}
+
+    function verifyProof(
+        uint[2] memory a,
+        uint[2][2] memory b,
+        uint[2] memory c,
+        uint[] memory input
+    ) public view returns (bool) {{
+        require(input.length == {n_public}, "verifier-bad-input-length");
+        Pairing.G1Point memory vk_x = vk.IC[0];
+        for (uint i = 0; i < input.length; i++) {{
+            vk_x = Pairing.addition(vk_x, Pairing.scalar_mul(vk.IC[i + 1], input[i]));
+        }}
+
+        Pairing.G1Point[] memory p1 = new Pairing.G1Point[](4);
+        Pairing.G2Point[] memory p2 = new Pairing.G2Point[](4);
+        p1[0] = Pairing.negate(Pairing.G1Point(a[0], a[1]));
+        p2[0] = Pairing.G2Point(b[0], b[1]);
+        p1[1] = vk.alpha;
+        p2[1] = vk.beta;
+        p1[2] = vk_x;
+        p2[2] = vk.gamma;
+        p1[3] = Pairing.G1Point(c[0], c[1]);
+        p2[3] = vk.delta;
+        return Pairing.pairing(p1, p2);
+    }}
+}}
+"#,
+            alpha_x = self.alpha_g1.x,
+            alpha_y = self.alpha_g1.y,
+            beta_x0 = self.beta_g2.x[0],
+            beta_x1 = self.beta_g2.x[1],
+            beta_y0 = self.beta_g2.y[0],
+            beta_y1 = self.beta_g2.y[1],
+            gamma_x0 = self.gamma_g2.x[0],
+            gamma_x1 = self.gamma_g2.x[1],
+            gamma_y0 = self.gamma_g2.y[0],
+            gamma_y1 = self.gamma_g2.y[1],
+            delta_x0 = self.delta_g2.x[0],
+            delta_x1 = self.delta_g2.x[1],
+            delta_y0 = self.delta_g2.y[0],
+            delta_y1 = self.delta_g2.y[1],
+            ic_len = self.ic.len(),
+            ic_entries = ic_entries,
+            n_public = n_public,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn g1(x: i64, y: i64) -> G1 {
+        G1 {
+            x: Integer::from(x),
+            y: Integer::from(y),
+        }
+    }
+
+    fn g2(x0: i64, x1: i64, y0: i64, y1: i64) -> G2 {
+        G2 {
+            x: [Integer::from(x0), Integer::from(x1)],
+            y: [Integer::from(y0), Integer::from(y1)],
+        }
+    }
+
+    fn sample_vk(n_public: usize) -> VerifyingKey {
+        VerifyingKey {
+            alpha_g1: g1(1, 2),
+            beta_g2: g2(3, 4, 5, 6),
+            gamma_g2: g2(7, 8, 9, 10),
+            delta_g2: g2(11, 12, 13, 14),
+            ic: (0..=n_public).map(|i| g1(i as i64, i as i64)).collect(),
+        }
+    }
+
+    #[test]
+    fn num_public_inputs_excludes_the_constant_ic_entry() {
+        assert_eq!(sample_vk(3).num_public_inputs().unwrap(), 3);
+    }
+
+    #[test]
+    fn num_public_inputs_errors_on_empty_ic() {
+        let mut vk = sample_vk(0);
+        vk.ic.clear();
+        assert!(vk.num_public_inputs().is_err());
+        assert!(vk.to_solidity_verifier().is_err());
+    }
+
+    #[test]
+    fn verify_proof_takes_a_dynamic_input_array() {
+        let sol = sample_vk(3).to_solidity_verifier().unwrap();
+        assert!(sol.contains("uint[] memory input"));
+        assert!(!sol.contains("uint[3] memory input"));
+        assert!(sol.contains("require(input.length == 3"));
+    }
+
+    #[test]
+    fn renders_one_ic_assignment_per_point() {
+        let sol = sample_vk(2).to_solidity_verifier().unwrap();
+        for i in 0..=2 {
+            assert!(sol.contains(&format!("vk.IC[{}] = Pairing.G1Point({}, {});", i, i, i)));
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! Compilation targets: lowering a front-end's `Computation` to a form a
+//! proving backend consumes, and exporting the result to external tooling.
+
+pub mod groth16;
+pub mod r1cs;
@@ -0,0 +1,185 @@
+//! Export an [`R1cs`] instance to the circom/snarkjs `.r1cs` binary container.
+//!
+//! The format is documented at
+//! <https://github.com/iden3/r1csfile/blob/master/doc/r1cs_bin_format.md>; we
+//! emit the three sections that downstream tooling (snarkjs, arkworks,
+//! rapidsnark) actually reads: header, constraints, and the wire-to-label map.
+//! We don't track per-wire debug labels, so the label map is just the
+//! identity on wire indices.
+
+use super::{Lc, R1cs, Var};
+use fxhash::FxHashMap;
+use rug::integer::Order;
+use rug::Integer;
+use std::io::{self, Write};
+
+const MAGIC: &[u8; 4] = b"r1cs";
+const VERSION: u32 = 1;
+const SECTION_HEADER: u32 = 1;
+const SECTION_CONSTRAINTS: u32 = 2;
+const SECTION_WIRE_TO_LABEL: u32 = 3;
+
+impl R1cs {
+    /// Write this R1CS out in the circom/snarkjs `.r1cs` binary format.
+    ///
+    /// `n_pub_out` and `n_pub_in` are the counts of public output and public
+    /// input wires; [`R1cs::vars`] is expected to list those variables first
+    /// (outputs, then inputs), in the same order `ProverData` classifies
+    /// them, with all remaining variables (private inputs and intermediate
+    /// wires) following. Wire 0 is always the constant "one".
+    ///
+    /// Returns an error rather than panicking if `n_pub_out + n_pub_in`
+    /// doesn't fit within the variable count, since callers currently
+    /// supply these counts from `ProverData`'s classification out of band
+    /// (tracked as a follow-up: `R1cs` doesn't yet expose that
+    /// classification itself, so this can't be derived or validated any
+    /// more precisely than a bounds check here).
+    pub fn write_circom_r1cs<W: Write>(
+        &self,
+        mut w: W,
+        n_pub_out: usize,
+        n_pub_in: usize,
+    ) -> io::Result<()> {
+        let n_wires = self.vars().len() + 1; // +1 for the constant wire.
+        let n_priv_in = (n_wires - 1).checked_sub(n_pub_out + n_pub_in).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "n_pub_out ({}) + n_pub_in ({}) exceeds the {} non-constant wires",
+                    n_pub_out,
+                    n_pub_in,
+                    n_wires - 1
+                ),
+            )
+        })?;
+
+        let modulus = self.field().modulus();
+        let field_size = modulus.to_digits::<u8>(Order::Lsf).len();
+
+        let wire_index: FxHashMap<Var, u32> = self
+            .vars()
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (*v, (i + 1) as u32))
+            .collect();
+
+        let mut header = Vec::new();
+        header.extend_from_slice(&(field_size as u32).to_le_bytes());
+        header.extend_from_slice(&raw_int_bytes(modulus, field_size));
+        header.extend_from_slice(&(n_wires as u32).to_le_bytes());
+        header.extend_from_slice(&(n_pub_out as u32).to_le_bytes());
+        header.extend_from_slice(&(n_pub_in as u32).to_le_bytes());
+        header.extend_from_slice(&(n_priv_in as u32).to_le_bytes());
+        header.extend_from_slice(&(n_wires as u64).to_le_bytes()); // nLabels: one per wire.
+        header.extend_from_slice(&(self.constraints().len() as u32).to_le_bytes());
+
+        let mut constraints = Vec::new();
+        for (a, b, c) in self.constraints() {
+            write_lc(&mut constraints, a, &wire_index, modulus, field_size);
+            write_lc(&mut constraints, b, &wire_index, modulus, field_size);
+            write_lc(&mut constraints, c, &wire_index, modulus, field_size);
+        }
+
+        let mut wire_to_label = Vec::new();
+        for i in 0..n_wires as u64 {
+            wire_to_label.extend_from_slice(&i.to_le_bytes());
+        }
+
+        w.write_all(MAGIC)?;
+        w.write_all(&VERSION.to_le_bytes())?;
+        w.write_all(&3u32.to_le_bytes())?; // nSections
+
+        write_section(&mut w, SECTION_HEADER, &header)?;
+        write_section(&mut w, SECTION_CONSTRAINTS, &constraints)?;
+        write_section(&mut w, SECTION_WIRE_TO_LABEL, &wire_to_label)?;
+
+        Ok(())
+    }
+}
+
+fn write_section<W: Write>(w: &mut W, ty: u32, body: &[u8]) -> io::Result<()> {
+    w.write_all(&ty.to_le_bytes())?;
+    w.write_all(&(body.len() as u64).to_le_bytes())?;
+    w.write_all(body)
+}
+
+fn write_lc(
+    out: &mut Vec<u8>,
+    lc: &Lc,
+    wire_index: &FxHashMap<Var, u32>,
+    modulus: &Integer,
+    field_size: usize,
+) {
+    let mut terms: Vec<(u32, Vec<u8>)> = Vec::new();
+    if lc.constant().i() != 0 {
+        terms.push((0, field_elem_bytes(&Integer::from(lc.constant().i()), modulus, field_size)));
+    }
+    for (var, coeff) in lc.monomials() {
+        let idx = *wire_index
+            .get(var)
+            .expect("constraint references a variable outside the wire ordering");
+        terms.push((idx, field_elem_bytes(&Integer::from(coeff.i()), modulus, field_size)));
+    }
+    out.extend_from_slice(&(terms.len() as u32).to_le_bytes());
+    for (idx, bytes) in terms {
+        out.extend_from_slice(&idx.to_le_bytes());
+        out.extend_from_slice(&bytes);
+    }
+}
+
+/// Serialize `v` (reduced mod `modulus`) as `field_size` little-endian bytes.
+fn field_elem_bytes(v: &Integer, modulus: &Integer, field_size: usize) -> Vec<u8> {
+    let mut v = v.clone();
+    v = (v % modulus).clone();
+    if v < 0 {
+        v += modulus.clone();
+    }
+    raw_int_bytes(&v, field_size)
+}
+
+/// Serialize a non-negative integer directly (no reduction) as `field_size`
+/// little-endian bytes. Used for the modulus itself, which must never be
+/// reduced against itself.
+fn raw_int_bytes(v: &Integer, field_size: usize) -> Vec<u8> {
+    let mut bytes = v.to_digits::<u8>(Order::Lsf);
+    bytes.resize(field_size, 0);
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_int_bytes_writes_the_modulus_unreduced() {
+        // A regression test for the bug where the header wrote
+        // `modulus % modulus` (i.e. all zeros) instead of the modulus
+        // itself.
+        let modulus = Integer::from(21888242871839275222246405745257275088548364400416034343698204186575808495617u128);
+        let bytes = raw_int_bytes(&modulus, 32);
+        assert_eq!(bytes.len(), 32);
+        assert_ne!(bytes, vec![0u8; 32]);
+        assert_eq!(Integer::from_digits(&bytes, Order::Lsf), modulus);
+    }
+
+    #[test]
+    fn field_elem_bytes_reduces_and_wraps_negatives() {
+        let modulus = Integer::from(17);
+        // 20 mod 17 == 3
+        assert_eq!(
+            field_elem_bytes(&Integer::from(20), &modulus, 4),
+            vec![3, 0, 0, 0]
+        );
+        // -1 mod 17 == 16
+        assert_eq!(
+            field_elem_bytes(&Integer::from(-1), &modulus, 4),
+            vec![16, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn field_elem_bytes_pads_to_field_size() {
+        let modulus = Integer::from(256);
+        assert_eq!(field_elem_bytes(&Integer::from(1), &modulus, 8).len(), 8);
+    }
+}
@@ -0,0 +1,173 @@
+//! Rank-1 constraint systems: the `A * B = C` representation most SNARK
+//! backends consume, plus the witness-computation bookkeeping needed to
+//! evaluate a circuit on concrete inputs.
+
+pub mod circom;
+
+use fxhash::FxHashMap;
+use rug::Integer;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A prime field, identified by its modulus.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Field {
+    modulus: Integer,
+}
+
+impl Field {
+    pub fn new(modulus: Integer) -> Self {
+        Field { modulus }
+    }
+
+    pub fn modulus(&self) -> &Integer {
+        &self.modulus
+    }
+}
+
+/// An element of a [`Field`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FieldV {
+    value: Integer,
+}
+
+impl FieldV {
+    pub fn new(value: Integer) -> Self {
+        FieldV { value }
+    }
+
+    /// This value truncated to an `i64`. Meant for human-readable output
+    /// and small-constant fast paths, not as a substitute for the full
+    /// field element when exactness matters over the whole modulus range.
+    pub fn i(&self) -> i64 {
+        self.value.to_i64().unwrap_or(0)
+    }
+}
+
+impl fmt::Display for FieldV {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+/// A variable (wire) in an R1CS instance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Var(pub usize);
+
+/// A linear combination of variables plus a constant: `c + sum(coeff * var)`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Lc {
+    constant: FieldV,
+    monomials: Vec<(Var, FieldV)>,
+}
+
+impl Lc {
+    pub fn new(constant: FieldV) -> Self {
+        Lc {
+            constant,
+            monomials: Vec::new(),
+        }
+    }
+
+    pub fn with_monomials(constant: FieldV, monomials: Vec<(Var, FieldV)>) -> Self {
+        Lc {
+            constant,
+            monomials,
+        }
+    }
+
+    pub fn constant(&self) -> &FieldV {
+        &self.constant
+    }
+
+    pub fn monomials(&self) -> impl Iterator<Item = (&Var, &FieldV)> {
+        self.monomials.iter().map(|(v, c)| (v, c))
+    }
+}
+
+/// The bookkeeping needed to evaluate a circuit's witness from its public
+/// and private inputs: how many sequential computation steps there are,
+/// and how many step arguments (intermediate values fed between steps)
+/// they take in total.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Precompute {
+    num_steps: usize,
+    num_step_args: usize,
+}
+
+impl Precompute {
+    pub fn new(num_steps: usize, num_step_args: usize) -> Self {
+        Precompute {
+            num_steps,
+            num_step_args,
+        }
+    }
+
+    pub fn num_steps(&self) -> usize {
+        self.num_steps
+    }
+
+    pub fn num_step_args(&self) -> usize {
+        self.num_step_args
+    }
+}
+
+/// A full rank-1 constraint system: the field it's defined over, its
+/// variables, their human-readable names, and its `(A, B, C)` constraints.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct R1cs {
+    field: Field,
+    vars: Vec<Var>,
+    names: FxHashMap<Var, String>,
+    constraints: Vec<(Lc, Lc, Lc)>,
+}
+
+impl R1cs {
+    pub fn new(field: Field) -> Self {
+        R1cs {
+            field,
+            vars: Vec::new(),
+            names: FxHashMap::default(),
+            constraints: Vec::new(),
+        }
+    }
+
+    pub fn field(&self) -> &Field {
+        &self.field
+    }
+
+    pub fn vars(&self) -> &[Var] {
+        &self.vars
+    }
+
+    pub fn names(&self) -> &FxHashMap<Var, String> {
+        &self.names
+    }
+
+    pub fn constraints(&self) -> &[(Lc, Lc, Lc)] {
+        &self.constraints
+    }
+
+    /// Allocate a fresh variable, optionally with a human-readable name for
+    /// debugging/inspection.
+    pub fn fresh_var(&mut self, name: Option<String>) -> Var {
+        let var = Var(self.vars.len());
+        self.vars.push(var);
+        if let Some(name) = name {
+            self.names.insert(var, name);
+        }
+        var
+    }
+
+    pub fn add_constraint(&mut self, a: Lc, b: Lc, c: Lc) {
+        self.constraints.push((a, b, c));
+    }
+}
+
+/// Everything a prover needs: the constraint system itself, plus the
+/// witness-computation bookkeeping to evaluate it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProverData {
+    pub r1cs: R1cs,
+    pub precompute: Precompute,
+}